@@ -0,0 +1,49 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use futures::task::AtomicWaker;
+
+#[derive(Debug, Default)]
+pub(super) struct Inner {
+    waker: AtomicWaker,
+    halted: AtomicBool,
+}
+
+impl Inner {
+    pub(super) fn register(&self, waker: &std::task::Waker) {
+        self.waker.register(waker);
+    }
+
+    pub(super) fn is_halted(&self) -> bool {
+        self.halted.load(Ordering::Relaxed)
+    }
+}
+
+/// A handle that can be used to halt an [`IntoStream`](super::IntoStream) from outside
+/// the task that is polling it.
+///
+/// Obtained through [`IntoStream::with_halt`](super::IntoStream::with_halt).
+#[derive(Debug, Clone)]
+pub struct HaltHandle {
+    inner: Arc<Inner>,
+}
+
+impl HaltHandle {
+    pub(super) fn new() -> (Self, Arc<Inner>) {
+        let inner = Arc::new(Inner::default());
+        (
+            HaltHandle {
+                inner: inner.clone(),
+            },
+            inner,
+        )
+    }
+
+    /// Signals the associated [`IntoStream`](super::IntoStream) to halt, causing it to cancel
+    /// its reader (if [`cancel_on_drop`](super::IntoStream) is set) and terminate the next time
+    /// it is polled, waking it up if necessary.
+    pub fn signal(&self) {
+        self.inner.halted.store(true, Ordering::Relaxed);
+        self.inner.waker.wake();
+    }
+}
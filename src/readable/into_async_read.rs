@@ -0,0 +1,134 @@
+use core::cmp;
+use core::pin::Pin;
+use std::io;
+
+use futures::io::{AsyncBufRead, AsyncRead};
+use futures::ready;
+use futures::stream::Stream;
+use futures::task::{Context, Poll};
+use js_sys::Uint8Array;
+use wasm_bindgen::{JsCast, UnwrapThrowExt};
+
+use super::util::js_to_io_error;
+use super::{IntoStream, ReadableStream, ReadableStreamDefaultReader};
+
+/// An [`AsyncRead`](futures::io::AsyncRead) and [`AsyncBufRead`](futures::io::AsyncBufRead)
+/// for the [`into_async_read`](super::ReadableStream::into_async_read) method.
+///
+/// This `AsyncRead` holds a reader, and therefore locks the [`ReadableStream`](super::ReadableStream).
+/// When this `AsyncRead` is dropped, it also drops its reader which in turn
+/// [releases its lock](https://streams.spec.whatwg.org/#release-a-lock).
+///
+/// When used through [`ReadableStream::into_async_read`](super::ReadableStream::into_async_read),
+/// the stream is automatically cancelled before dropping the reader, discarding any pending read requests.
+/// When used through [`ReadableStreamDefaultReader::into_async_read`](super::ReadableStreamDefaultReader::into_async_read),
+/// it is up to the user to ensure that there are no pending read requests when dropped;
+/// `IntoAsyncRead` itself has no method to cancel the stream.
+#[must_use = "readers do nothing unless polled"]
+#[derive(Debug)]
+pub struct IntoAsyncRead<'reader> {
+    stream: IntoStream<'reader>,
+    leftover: Option<(Vec<u8>, usize)>,
+}
+
+impl<'reader> IntoAsyncRead<'reader> {
+    #[inline]
+    pub(super) fn new(stream: IntoStream<'reader>) -> Self {
+        IntoAsyncRead {
+            stream,
+            leftover: None,
+        }
+    }
+}
+
+impl<'stream> ReadableStream<'stream> {
+    /// Converts this `ReadableStream` into an [`AsyncRead`](futures::io::AsyncRead) and
+    /// [`AsyncBufRead`](futures::io::AsyncBufRead), treating each chunk as a
+    /// [`Uint8Array`](js_sys::Uint8Array).
+    ///
+    /// Equivalent to `self.into_stream().into_async_read()`.
+    #[inline]
+    pub fn into_async_read(self) -> IntoAsyncRead<'stream> {
+        self.into_stream().into_async_read()
+    }
+}
+
+impl<'reader> ReadableStreamDefaultReader<'reader> {
+    /// Converts this `ReadableStreamDefaultReader` into an [`AsyncRead`](futures::io::AsyncRead)
+    /// and [`AsyncBufRead`](futures::io::AsyncBufRead), treating each chunk as a
+    /// [`Uint8Array`](js_sys::Uint8Array).
+    ///
+    /// Equivalent to `self.into_stream().into_async_read()`.
+    #[inline]
+    pub fn into_async_read(self) -> IntoAsyncRead<'reader> {
+        self.into_stream().into_async_read()
+    }
+}
+
+impl<'reader> AsyncRead for IntoAsyncRead<'reader> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        // Serve from the leftover chunk first, if there is one.
+        if let Some((chunk, cursor)) = &mut self.leftover {
+            let len = cmp::min(buf.len(), chunk.len() - *cursor);
+            buf[..len].copy_from_slice(&chunk[*cursor..*cursor + len]);
+            *cursor += len;
+            if *cursor == chunk.len() {
+                self.leftover = None;
+            }
+            return Poll::Ready(Ok(len));
+        }
+
+        // No leftover, read the next chunk from the underlying stream.
+        match ready!(Pin::new(&mut self.stream).poll_next(cx)) {
+            None => Poll::Ready(Ok(0)),
+            Some(Err(js_value)) => Poll::Ready(Err(js_to_io_error(js_value))),
+            Some(Ok(js_value)) => {
+                let chunk = js_value.unchecked_into::<Uint8Array>();
+                let mut bytes = vec![0; chunk.length() as usize];
+                chunk.copy_to(&mut bytes);
+
+                let len = cmp::min(buf.len(), bytes.len());
+                buf[..len].copy_from_slice(&bytes[..len]);
+                if len < bytes.len() {
+                    self.leftover = Some((bytes, len));
+                }
+                Poll::Ready(Ok(len))
+            }
+        }
+    }
+}
+
+impl<'reader> AsyncBufRead for IntoAsyncRead<'reader> {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        let this = self.get_mut();
+        if this.leftover.is_none() {
+            match ready!(Pin::new(&mut this.stream).poll_next(cx)) {
+                None => return Poll::Ready(Ok(&[])),
+                Some(Err(js_value)) => return Poll::Ready(Err(js_to_io_error(js_value))),
+                Some(Ok(js_value)) => {
+                    let chunk = js_value.unchecked_into::<Uint8Array>();
+                    let mut bytes = vec![0; chunk.length() as usize];
+                    chunk.copy_to(&mut bytes);
+                    this.leftover = Some((bytes, 0));
+                }
+            }
+        }
+
+        let (chunk, cursor) = this.leftover.as_ref().unwrap_throw();
+        Poll::Ready(Ok(&chunk[*cursor..]))
+    }
+
+    fn consume(self: Pin<&mut Self>, amount: usize) {
+        let this = self.get_mut();
+        if let Some((chunk, cursor)) = &mut this.leftover {
+            *cursor += amount;
+            if *cursor >= chunk.len() {
+                this.leftover = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use core::cmp;
+use core::pin::Pin;
+use std::io;
+
+use futures::future::FutureExt;
+use futures::io::AsyncRead;
+use futures::ready;
+use futures::task::{Context, Poll};
+use js_sys::{ArrayBuffer, Uint8Array};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+use super::sys::ReadableStreamBYOBReadResult;
+use super::util::js_to_io_error;
+use super::{ReadableStream, ReadableStreamBYOBReader};
+
+/// A zero-copy [`AsyncRead`](futures::io::AsyncRead) for the
+/// [`try_into_async_read_byob`](super::ReadableStream::try_into_async_read_byob) method.
+///
+/// Unlike [`IntoAsyncRead`](super::IntoAsyncRead), this reads directly into a reusable
+/// `ArrayBuffer` through a [`ReadableStreamBYOBReader`], avoiding a fresh allocation for every
+/// chunk once the stream is in steady state.
+///
+/// This `AsyncRead` holds a reader, and therefore locks the [`ReadableStream`](super::ReadableStream).
+/// When this `AsyncRead` is dropped, it also drops its reader which in turn
+/// [releases its lock](https://streams.spec.whatwg.org/#release-a-lock).
+#[must_use = "readers do nothing unless polled"]
+#[derive(Debug)]
+pub struct IntoAsyncReadBYOB<'reader> {
+    reader: Option<ReadableStreamBYOBReader<'reader>>,
+    buffer: Option<ArrayBuffer>,
+    fut: Option<JsFuture>,
+    cancel_on_drop: bool,
+}
+
+impl<'reader> IntoAsyncReadBYOB<'reader> {
+    #[inline]
+    pub(super) fn new(
+        reader: ReadableStreamBYOBReader<'reader>,
+        cancel_on_drop: bool,
+    ) -> IntoAsyncReadBYOB<'reader> {
+        IntoAsyncReadBYOB {
+            reader: Some(reader),
+            buffer: None,
+            fut: None,
+            cancel_on_drop,
+        }
+    }
+}
+
+impl<'stream> ReadableStream<'stream> {
+    /// Attempts to convert this `ReadableStream` into a zero-copy [`AsyncRead`] that reads
+    /// directly into a reusable buffer via a [`ReadableStreamBYOBReader`].
+    ///
+    /// Returns `Err(self)` if this stream does not support BYOB reads, i.e. if
+    /// `getReader({ mode: "byob" })` is not supported.
+    pub fn try_into_async_read_byob(self) -> Result<IntoAsyncReadBYOB<'stream>, Self> {
+        self.try_get_reader_byob()
+            .map(|reader| IntoAsyncReadBYOB::new(reader, true))
+    }
+}
+
+impl<'reader> ReadableStreamBYOBReader<'reader> {
+    /// Converts this `ReadableStreamBYOBReader` into a zero-copy [`AsyncRead`] that reads
+    /// directly into a reusable buffer.
+    #[inline]
+    pub fn into_async_read(self) -> IntoAsyncReadBYOB<'reader> {
+        IntoAsyncReadBYOB::new(self, false)
+    }
+}
+
+impl<'reader> AsyncRead for IntoAsyncReadBYOB<'reader> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        if buf.is_empty() {
+            // A BYOB `read()` with a zero-length view rejects per spec; nothing to read anyway.
+            return Poll::Ready(Ok(0));
+        }
+
+        loop {
+            if self.fut.is_none() {
+                let this = &mut *self;
+                let reader = match &this.reader {
+                    Some(reader) => reader,
+                    None => return Poll::Ready(Ok(0)),
+                };
+
+                // Recycle the buffer from the previous read if it's still big enough for this
+                // call's (possibly different-sized) `buf`, otherwise allocate a new one.
+                let buffer = this
+                    .buffer
+                    .take()
+                    .filter(|buffer| buffer.byte_length() >= buf.len() as u32)
+                    .unwrap_or_else(|| ArrayBuffer::new(buf.len() as u32));
+                let view = Uint8Array::new_with_byte_offset_and_length(&buffer, 0, buf.len() as u32);
+                let fut = JsFuture::from(reader.as_raw().read_with_array_buffer_view(&view));
+                this.fut = Some(fut);
+            }
+
+            // Poll the future for the pending read
+            let js_result = ready!(self.as_mut().fut.as_mut().unwrap_throw().poll_unpin(cx));
+            self.fut = None;
+
+            match js_result {
+                Err(js_value) => {
+                    self.reader = None;
+                    return Poll::Ready(Err(js_to_io_error(js_value)));
+                }
+                Ok(js_value) => {
+                    let result = ReadableStreamBYOBReadResult::from(js_value);
+                    if result.is_done() {
+                        self.reader = None;
+                        return Poll::Ready(Ok(0));
+                    }
+
+                    // The reader hands back a (possibly reallocated) view into a transferred
+                    // `ArrayBuffer`; copy out the filled region and recycle the buffer.
+                    let view = result.value();
+                    let len = cmp::min(buf.len(), view.length() as usize);
+                    view.copy_to(&mut buf[..len]);
+                    self.buffer = Some(view.buffer());
+                    return Poll::Ready(Ok(len));
+                }
+            }
+        }
+    }
+}
+
+impl<'reader> Drop for IntoAsyncReadBYOB<'reader> {
+    fn drop(&mut self) {
+        if self.cancel_on_drop {
+            if let Some(reader) = self.reader.take() {
+                let _ = reader.as_raw().cancel().catch(&Closure::once(|_| {}));
+            }
+        }
+    }
+}
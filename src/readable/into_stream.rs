@@ -1,14 +1,18 @@
 use core::pin::Pin;
+use std::collections::VecDeque;
+use std::sync::Arc;
 
-use futures::future::FutureExt;
+use futures::future::{Future, FutureExt};
 use futures::ready;
 use futures::stream::{FusedStream, Stream};
 use futures::task::{Context, Poll};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::JsFuture;
 
+use super::halt::{HaltHandle, Inner as HaltInner};
 use super::sys::ReadableStreamReadResult;
-use super::ReadableStreamDefaultReader;
+use super::take_until::TakeUntil;
+use super::{IntoAsyncRead, ReadableStreamDefaultReader};
 
 /// A [`Stream`](futures::Stream) for the [`into_stream`](super::ReadableStream::into_stream) method.
 ///
@@ -26,8 +30,10 @@ use super::ReadableStreamDefaultReader;
 #[derive(Debug)]
 pub struct IntoStream<'reader> {
     reader: Option<ReadableStreamDefaultReader<'reader>>,
-    fut: Option<JsFuture>,
+    pending: VecDeque<JsFuture>,
     cancel_on_drop: bool,
+    halt: Option<Arc<HaltInner>>,
+    prefetch: usize,
 }
 
 impl<'reader> IntoStream<'reader> {
@@ -35,11 +41,35 @@ impl<'reader> IntoStream<'reader> {
     pub(super) fn new(reader: ReadableStreamDefaultReader, cancel_on_drop: bool) -> IntoStream {
         IntoStream {
             reader: Some(reader),
-            fut: None,
+            pending: VecDeque::new(),
             cancel_on_drop,
+            halt: None,
+            prefetch: 1,
         }
     }
 
+    /// Keeps up to `depth` `read()` requests in flight at once, instead of awaiting each chunk
+    /// before requesting the next one.
+    ///
+    /// This hides the per-chunk read latency for streams whose source can produce many chunks
+    /// eagerly, at the cost of buffering up to `depth` chunks ahead of the consumer. Read order
+    /// is preserved. A `depth` of `0` is treated as `1`, the default.
+    pub fn with_prefetch(mut self, depth: usize) -> Self {
+        self.prefetch = depth.max(1);
+        self
+    }
+
+    /// Equips this `Stream` with a [`HaltHandle`] that can be used to halt it from another task,
+    /// even while it is blocked awaiting a pending read.
+    ///
+    /// Once [`HaltHandle::signal`] is called, the stream cancels its reader
+    /// (honoring [`cancel_on_drop`](Self::new)) and terminates the next time it is polled.
+    pub fn with_halt(mut self) -> (Self, HaltHandle) {
+        let (handle, inner) = HaltHandle::new();
+        self.halt = Some(inner);
+        (self, handle)
+    }
+
     /// [Cancels](https://streams.spec.whatwg.org/#cancel-a-readable-stream) the stream,
     /// signaling a loss of interest in the stream by a consumer.
     pub async fn cancel(mut self) -> Result<(), JsValue> {
@@ -57,11 +87,48 @@ impl<'reader> IntoStream<'reader> {
             None => Ok(()),
         }
     }
+
+    /// Converts this `Stream` into an [`AsyncRead`](futures::io::AsyncRead), treating each chunk
+    /// as a [`Uint8Array`](js_sys::Uint8Array).
+    ///
+    /// This is only valid for a stream of bytes, i.e. a stream that actually yields `Uint8Array`
+    /// chunks. Other chunk types will be misinterpreted as a `Uint8Array`.
+    #[inline]
+    pub fn into_async_read(self) -> IntoAsyncRead<'reader> {
+        IntoAsyncRead::new(self)
+    }
+
+    /// Yields chunks from this `Stream` until `fut` resolves, then [cancels](Self::cancel)
+    /// the underlying reader (honoring [`cancel_on_drop`](Self::new); without it, the reader
+    /// is only dropped) and terminates.
+    ///
+    /// This is the idiomatic way to bound a stream by an external event, such as an
+    /// `AbortSignal` future, a timeout, or a "stop" channel.
+    #[inline]
+    pub fn take_until<Fut>(self, fut: Fut) -> TakeUntil<'reader, Fut>
+    where
+        Fut: Future,
+    {
+        TakeUntil::new(self, fut)
+    }
+
+    /// Cancels the reader (honoring [`cancel_on_drop`](Self::new)) and discards any in-flight
+    /// reads, without dropping `self`.
+    pub(super) fn halt_reader(&mut self) {
+        if self.cancel_on_drop {
+            if let Some(reader) = self.reader.take() {
+                let _ = reader.as_raw().cancel().catch(&Closure::once(|_| {}));
+            }
+        } else {
+            self.reader = None;
+        }
+        self.pending.clear();
+    }
 }
 
 impl FusedStream for IntoStream<'_> {
     fn is_terminated(&self) -> bool {
-        self.reader.is_none() && self.fut.is_none()
+        self.reader.is_none() && self.pending.is_empty()
     }
 }
 
@@ -69,40 +136,49 @@ impl<'reader> Stream for IntoStream<'reader> {
     type Item = Result<JsValue, JsValue>;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        if self.fut.is_none() {
-            // No pending read, start reading the next chunk
-            match &self.reader {
-                Some(reader) => {
-                    // Read a chunk and store its future
-                    let fut = JsFuture::from(reader.as_raw().read());
-                    self.fut = Some(fut);
-                }
-                None => {
-                    // Reader was already dropped
-                    return Poll::Ready(None);
-                }
+        if let Some(halt) = &self.halt {
+            halt.register(cx.waker());
+            if halt.is_halted() {
+                // Halted from another task, cancel (if requested) and terminate
+                self.halt_reader();
+                return Poll::Ready(None);
+            }
+        }
+
+        let this = &mut *self;
+        if let Some(reader) = &this.reader {
+            // Top the pipeline back up to the requested prefetch depth
+            while this.pending.len() < this.prefetch {
+                let fut = JsFuture::from(reader.as_raw().read());
+                this.pending.push_back(fut);
             }
         }
 
-        // Poll the future for the pending read
-        let js_result = ready!(self.as_mut().fut.as_mut().unwrap_throw().poll_unpin(cx));
-        self.fut = None;
+        // Poll the future for the oldest pending read
+        let fut = match self.pending.front_mut() {
+            Some(fut) => fut,
+            None => return Poll::Ready(None), // Reader was already dropped
+        };
+        let js_result = ready!(fut.poll_unpin(cx));
+        self.pending.pop_front();
 
         // Read completed
         Poll::Ready(match js_result {
             Ok(js_value) => {
                 let result = ReadableStreamReadResult::from(js_value);
                 if result.is_done() {
-                    // End of stream, drop reader
+                    // End of stream, drop reader and discard any other in-flight reads
                     self.reader = None;
+                    self.pending.clear();
                     None
                 } else {
                     Some(Ok(result.value()))
                 }
             }
             Err(js_value) => {
-                // Error, drop reader
+                // Error, drop reader and discard any other in-flight reads
                 self.reader = None;
+                self.pending.clear();
                 Some(Err(js_value))
             }
         })
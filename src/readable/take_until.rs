@@ -0,0 +1,63 @@
+use core::pin::Pin;
+
+use futures::future::Future;
+use futures::stream::{FusedStream, Stream};
+use futures::task::{Context, Poll};
+use wasm_bindgen::prelude::*;
+
+use super::IntoStream;
+
+/// A [`Stream`](futures::Stream) for the [`take_until`](IntoStream::take_until) method.
+#[must_use = "streams do nothing unless polled"]
+#[derive(Debug)]
+pub struct TakeUntil<'reader, Fut> {
+    stream: IntoStream<'reader>,
+    stop: Option<Fut>,
+}
+
+impl<'reader, Fut> TakeUntil<'reader, Fut> {
+    #[inline]
+    pub(super) fn new(stream: IntoStream<'reader>, stop: Fut) -> TakeUntil<'reader, Fut> {
+        TakeUntil {
+            stream,
+            stop: Some(stop),
+        }
+    }
+}
+
+impl<'reader, Fut> FusedStream for TakeUntil<'reader, Fut>
+where
+    Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        // Either the stopping future already fired, or the underlying stream hit its own
+        // natural EOF (in which case it keeps yielding `None` regardless of `stop`).
+        self.stop.is_none() || self.stream.is_terminated()
+    }
+}
+
+impl<'reader, Fut> Stream for TakeUntil<'reader, Fut>
+where
+    Fut: Future,
+{
+    type Item = Result<JsValue, JsValue>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // Safety: `stop` is only ever polled in place through this pin, and is never moved out
+        // while pinned (dropping it in place via `this.stop = None` does not move it).
+        let this = unsafe { self.get_unchecked_mut() };
+
+        if let Some(stop) = this.stop.as_mut() {
+            let stop = unsafe { Pin::new_unchecked(stop) };
+            if stop.poll(cx).is_ready() {
+                // The stopping future resolved, cancel the underlying reader (if requested)
+                // and terminate
+                this.stop = None;
+                this.stream.halt_reader();
+                return Poll::Ready(None);
+            }
+        }
+
+        Pin::new(&mut this.stream).poll_next(cx)
+    }
+}
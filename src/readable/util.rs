@@ -0,0 +1,7 @@
+use wasm_bindgen::JsValue;
+
+/// Converts a rejected JS promise value into an [`io::Error`](std::io::Error), for use by the
+/// `AsyncRead` adapters that bridge byte `ReadableStream`s to `futures::io`.
+pub(super) fn js_to_io_error(js_value: JsValue) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::Other, format!("{js_value:?}"))
+}
@@ -0,0 +1,29 @@
+use futures::stream::StreamExt;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+use wasm_bindgen_test::*;
+
+mod support;
+use support::readable_stream_from_array_then_hang;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn halt_terminates_a_stream_blocked_on_a_pending_read() {
+    let chunks: js_sys::Array = [JsValue::from_f64(1.0)].into_iter().collect();
+    let readable = readable_stream_from_array_then_hang(chunks);
+
+    let (stream, handle) = readable.into_stream().with_halt();
+    let mut stream = Box::pin(stream);
+
+    // The one chunk the source will ever produce.
+    assert_eq!(stream.next().await.unwrap().unwrap().as_f64(), Some(1.0));
+
+    // The next `read()` is now pending forever (the source never closes). Signal a halt from
+    // a separate task while `stream.next()` is genuinely blocked awaiting that read, and
+    // confirm it wakes the task up and terminates the stream.
+    spawn_local(async move {
+        handle.signal();
+    });
+    assert_eq!(stream.next().await, None);
+}
@@ -0,0 +1,66 @@
+use core::pin::Pin;
+
+use futures::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt};
+use js_sys::Uint8Array;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+mod support;
+use support::{readable_stream_from_array, readable_stream_from_array_then_error};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn chunk(bytes: &[u8]) -> JsValue {
+    Uint8Array::from(bytes).into()
+}
+
+#[wasm_bindgen_test]
+async fn splits_leftover_across_small_reads() {
+    let chunks: js_sys::Array = [chunk(&[1, 2, 3]), chunk(&[4, 5])].into_iter().collect();
+    let readable = readable_stream_from_array(chunks);
+
+    let mut reader = readable.into_stream().into_async_read();
+    let mut out = Vec::new();
+    // Two-byte reads split the three-byte chunk, leaving one byte for the next read.
+    let mut buf = [0u8; 2];
+    loop {
+        let n = reader.read(&mut buf).await.unwrap();
+        if n == 0 {
+            break;
+        }
+        out.extend_from_slice(&buf[..n]);
+    }
+
+    assert_eq!(out, vec![1, 2, 3, 4, 5]);
+}
+
+#[wasm_bindgen_test]
+async fn poll_fill_buf_and_consume_track_the_leftover_cursor() {
+    let chunks: js_sys::Array = [chunk(&[1, 2, 3, 4])].into_iter().collect();
+    let readable = readable_stream_from_array(chunks);
+
+    let mut reader = readable.into_stream().into_async_read();
+
+    assert_eq!(reader.fill_buf().await.unwrap(), &[1, 2, 3, 4]);
+    Pin::new(&mut reader).consume(2);
+
+    assert_eq!(reader.fill_buf().await.unwrap(), &[3, 4]);
+    Pin::new(&mut reader).consume(2);
+
+    assert_eq!(reader.fill_buf().await.unwrap(), &[] as &[u8]);
+}
+
+#[wasm_bindgen_test]
+async fn maps_stream_error_to_io_error() {
+    let chunks: js_sys::Array = [chunk(&[1])].into_iter().collect();
+    let readable = readable_stream_from_array_then_error(chunks, JsValue::from_str("boom"));
+
+    let mut reader = readable.into_stream().into_async_read();
+
+    let mut buf = [0u8; 1];
+    reader.read_exact(&mut buf).await.unwrap();
+    assert_eq!(buf, [1]);
+
+    let err = reader.read_exact(&mut buf).await.unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::Other);
+}
@@ -0,0 +1,65 @@
+use futures::io::AsyncReadExt;
+use js_sys::Uint8Array;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+mod support;
+use support::{readable_byte_stream_from_array, readable_byte_stream_from_array_then_error};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn chunk(bytes: &[u8]) -> JsValue {
+    Uint8Array::from(bytes).into()
+}
+
+#[wasm_bindgen_test]
+async fn recycles_and_grows_the_buffer_across_reads() {
+    let chunks: js_sys::Array = [chunk(&[1, 2]), chunk(&[3, 4, 5, 6])].into_iter().collect();
+    let readable = readable_byte_stream_from_array(chunks);
+
+    let mut reader = readable
+        .try_into_async_read_byob()
+        .unwrap_or_else(|_| panic!("BYOB reads should be supported"));
+
+    // First read allocates a small buffer...
+    let mut small = [0u8; 2];
+    assert_eq!(reader.read(&mut small).await.unwrap(), 2);
+    assert_eq!(small, [1, 2]);
+
+    // ...which must grow to serve a larger request on the next call.
+    let mut large = [0u8; 4];
+    assert_eq!(reader.read(&mut large).await.unwrap(), 4);
+    assert_eq!(large, [3, 4, 5, 6]);
+
+    assert_eq!(reader.read(&mut large).await.unwrap(), 0);
+}
+
+#[wasm_bindgen_test]
+async fn zero_length_read_returns_immediately_without_touching_the_reader() {
+    let chunks: js_sys::Array = [chunk(&[1])].into_iter().collect();
+    let readable = readable_byte_stream_from_array(chunks);
+
+    let mut reader = readable
+        .try_into_async_read_byob()
+        .unwrap_or_else(|_| panic!("BYOB reads should be supported"));
+
+    assert_eq!(reader.read(&mut []).await.unwrap(), 0);
+
+    let mut buf = [0u8; 1];
+    assert_eq!(reader.read(&mut buf).await.unwrap(), 1);
+    assert_eq!(buf, [1]);
+}
+
+#[wasm_bindgen_test]
+async fn maps_stream_error_to_io_error() {
+    let chunks: js_sys::Array = js_sys::Array::new();
+    let readable = readable_byte_stream_from_array_then_error(chunks, JsValue::from_str("boom"));
+
+    let mut reader = readable
+        .try_into_async_read_byob()
+        .unwrap_or_else(|_| panic!("BYOB reads should be supported"));
+
+    let mut buf = [0u8; 1];
+    let err = reader.read(&mut buf).await.unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::Other);
+}
@@ -0,0 +1,25 @@
+use futures::stream::StreamExt;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+mod support;
+use support::readable_stream_from_array;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn prefetch_preserves_read_order() {
+    let chunks: js_sys::Array = [1.0, 2.0, 3.0, 4.0]
+        .iter()
+        .map(|&n| JsValue::from_f64(n))
+        .collect();
+    let readable = readable_stream_from_array(chunks);
+
+    let mut stream = Box::pin(readable.into_stream().with_prefetch(3));
+    let mut values = Vec::new();
+    while let Some(item) = stream.next().await {
+        values.push(item.unwrap().as_f64().unwrap());
+    }
+
+    assert_eq!(values, vec![1.0, 2.0, 3.0, 4.0]);
+}
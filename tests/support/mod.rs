@@ -0,0 +1,128 @@
+use js_sys::{Array, Function};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_streams::ReadableStream;
+
+/// Builds a `ReadableStream` that enqueues `chunks`, in order, then closes.
+pub fn readable_stream_from_array(chunks: Array) -> ReadableStream {
+    build(
+        chunks,
+        "(chunks) => new ReadableStream({
+            pull(controller) {
+                if (chunks.length > 0) {
+                    controller.enqueue(chunks.shift());
+                } else {
+                    controller.close();
+                }
+            },
+        })",
+    )
+}
+
+/// Builds a `ReadableStream` that enqueues `chunks`, in order, and then neither closes nor
+/// errors, leaving any further read permanently pending. Used to simulate a stream that is
+/// still blocked on an in-flight read from the source.
+pub fn readable_stream_from_array_then_hang(chunks: Array) -> ReadableStream {
+    build(
+        chunks,
+        "(chunks) => new ReadableStream({
+            pull(controller) {
+                if (chunks.length > 0) {
+                    controller.enqueue(chunks.shift());
+                }
+                // Otherwise: never enqueue or close, so this read stays pending forever.
+            },
+        })",
+    )
+}
+
+/// Builds a `ReadableStream` that enqueues `chunks`, in order, then errors with `error`.
+pub fn readable_stream_from_array_then_error(chunks: Array, error: JsValue) -> ReadableStream {
+    let factory: Function = js_sys::eval(
+        "(chunks, error) => new ReadableStream({
+            pull(controller) {
+                if (chunks.length > 0) {
+                    controller.enqueue(chunks.shift());
+                } else {
+                    controller.error(error);
+                }
+            },
+        })",
+    )
+    .unwrap()
+    .unchecked_into();
+    let raw = factory
+        .call2(&JsValue::undefined(), &chunks, &error)
+        .unwrap()
+        .unchecked_into();
+    ReadableStream::from_raw(raw)
+}
+
+/// Builds a byte-typed `ReadableStream` (supports BYOB readers) that fills each `byobRequest`
+/// from `chunks`, in order, then closes. Each chunk must fit within the requested view.
+pub fn readable_byte_stream_from_array(chunks: Array) -> ReadableStream {
+    build(
+        chunks,
+        "(chunks) => new ReadableStream({
+            type: 'bytes',
+            pull(controller) {
+                if (chunks.length === 0) {
+                    controller.close();
+                    return;
+                }
+                const chunk = chunks.shift();
+                const request = controller.byobRequest;
+                const view = new Uint8Array(
+                    request.view.buffer,
+                    request.view.byteOffset,
+                    request.view.byteLength,
+                );
+                view.set(chunk);
+                request.respondWithNewView(view.subarray(0, chunk.length));
+            },
+        })",
+    )
+}
+
+/// Builds a byte-typed `ReadableStream` that fills each `byobRequest` from `chunks`, in order,
+/// then errors with `error`.
+pub fn readable_byte_stream_from_array_then_error(
+    chunks: Array,
+    error: JsValue,
+) -> ReadableStream {
+    let factory: Function = js_sys::eval(
+        "(chunks, error) => new ReadableStream({
+            type: 'bytes',
+            pull(controller) {
+                if (chunks.length === 0) {
+                    controller.error(error);
+                    return;
+                }
+                const chunk = chunks.shift();
+                const request = controller.byobRequest;
+                const view = new Uint8Array(
+                    request.view.buffer,
+                    request.view.byteOffset,
+                    request.view.byteLength,
+                );
+                view.set(chunk);
+                request.respondWithNewView(view.subarray(0, chunk.length));
+            },
+        })",
+    )
+    .unwrap()
+    .unchecked_into();
+    let raw = factory
+        .call2(&JsValue::undefined(), &chunks, &error)
+        .unwrap()
+        .unchecked_into();
+    ReadableStream::from_raw(raw)
+}
+
+fn build(chunks: Array, source_factory: &str) -> ReadableStream {
+    let factory: Function = js_sys::eval(source_factory).unwrap().unchecked_into();
+    let raw = factory
+        .call1(&JsValue::undefined(), &chunks)
+        .unwrap()
+        .unchecked_into();
+    ReadableStream::from_raw(raw)
+}
@@ -0,0 +1,41 @@
+use futures::channel::oneshot;
+use futures::stream::{FusedStream, StreamExt};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::spawn_local;
+use wasm_bindgen_test::*;
+
+mod support;
+use support::{readable_stream_from_array, readable_stream_from_array_then_hang};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn take_until_stops_on_signal() {
+    let chunks: js_sys::Array = [JsValue::from_f64(1.0)].into_iter().collect();
+    let readable = readable_stream_from_array_then_hang(chunks);
+
+    let (tx, rx) = oneshot::channel::<()>();
+    let mut stream = Box::pin(readable.into_stream().take_until(rx));
+
+    assert_eq!(stream.next().await.unwrap().unwrap().as_f64(), Some(1.0));
+
+    // The next chunk never arrives; firing `tx` should still unblock and end the stream.
+    spawn_local(async move {
+        let _ = tx.send(());
+    });
+    assert_eq!(stream.next().await, None);
+}
+
+#[wasm_bindgen_test]
+async fn take_until_fuses_on_natural_eof_even_if_the_signal_never_fires() {
+    let chunks: js_sys::Array = [JsValue::from_f64(1.0)].into_iter().collect();
+    let readable = readable_stream_from_array(chunks);
+
+    // `rx`'s sender is kept alive but never sent to, so the stopping future never resolves.
+    let (_tx, rx) = oneshot::channel::<()>();
+    let mut stream = Box::pin(readable.into_stream().take_until(rx));
+
+    assert_eq!(stream.next().await.unwrap().unwrap().as_f64(), Some(1.0));
+    assert_eq!(stream.next().await, None);
+    assert!(stream.is_terminated());
+}